@@ -0,0 +1,104 @@
+//! Counts heap allocations made while serving a request through `SLogger`.
+//!
+//! Run with `cargo bench --bench allocations`.
+
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use actix_web::{App, test, web};
+use actix_web_middleware_slogger::{Fields, SLogger};
+
+struct CountingAllocator;
+
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        unsafe { System.alloc(layout) }
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        unsafe { System.dealloc(ptr, layout) }
+    }
+}
+
+#[global_allocator]
+static GLOBAL: CountingAllocator = CountingAllocator;
+
+/// Logger that visits every key-value like a real one would, without allocating.
+struct VisitingLogger;
+
+impl log::Log for VisitingLogger {
+    fn enabled(&self, _: &log::Metadata) -> bool {
+        true
+    }
+
+    fn log(&self, record: &log::Record) {
+        std::hint::black_box(record.key_values().count());
+    }
+
+    fn flush(&self) {}
+}
+
+const REQUESTS: usize = 10_000;
+
+async fn allocations_per_request(name: &str, logger: Option<SLogger>) -> usize {
+    let app = App::new().route("/users/{id}", web::get().to(|| async { "Hello world!" }));
+    let allocations = match logger {
+        Some(logger) => run(test::init_service(app.wrap(logger)).await).await,
+        None => run(test::init_service(app).await).await,
+    };
+    println!("{name:<24} {allocations:>6} allocations/request");
+    allocations
+}
+
+async fn run<S, B>(app: S) -> usize
+where
+    S: actix_web::dev::Service<
+            actix_http::Request,
+            Response = actix_web::dev::ServiceResponse<B>,
+            Error = actix_web::Error,
+        >,
+    B: actix_web::body::MessageBody,
+{
+    let before = ALLOCATIONS.load(Ordering::Relaxed);
+    for _ in 0..REQUESTS {
+        let req = test::TestRequest::get()
+            .uri("/users/42?search=actix")
+            .insert_header(("user-agent", "bench"))
+            .insert_header(("referer", "https://actix.rs"))
+            .to_request();
+        test::call_and_read_body(&app, req).await;
+    }
+    (ALLOCATIONS.load(Ordering::Relaxed) - before) / REQUESTS
+}
+
+fn main() {
+    log::set_logger(&VisitingLogger).unwrap();
+    log::set_max_level(log::LevelFilter::Trace);
+
+    actix_web::rt::System::new().block_on(async {
+        let baseline = allocations_per_request("no middleware", None).await;
+        for (name, logger) in [
+            ("default fields", SLogger::default()),
+            (
+                "method/path/status",
+                SLogger::new(
+                    Fields::builder()
+                        .with_method()
+                        .with_path()
+                        .with_status()
+                        .build(),
+                ),
+            ),
+        ] {
+            let allocations = allocations_per_request(name, Some(logger)).await;
+            println!(
+                "{:<24} {:>6} allocations/request added by SLogger",
+                "",
+                allocations - baseline
+            );
+        }
+    });
+}
@@ -0,0 +1,44 @@
+//! Measures repeated `connection_info()` lookups made while rendering `host`, `remote_addr`
+//! and `real_ip`.
+//!
+//! actix-web parses the forwarded headers on the first call and caches the result in the
+//! request extensions, so later calls only look it up.
+//!
+//! Run with `cargo bench --bench connection_info`.
+
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+use actix_web::dev::ServiceRequest;
+use actix_web::test::TestRequest;
+
+const REQUESTS: u32 = 100_000;
+
+fn request() -> ServiceRequest {
+    TestRequest::get()
+        .uri("/users/42")
+        .insert_header(("forwarded", "for=192.0.2.60;proto=https;host=example.com"))
+        .insert_header(("x-forwarded-for", "203.0.113.195, 70.41.3.18"))
+        .to_srv_request()
+}
+
+/// Time `lookups` calls of `connection_info()` on each of `REQUESTS` fresh requests.
+fn measure(lookups: usize) -> Duration {
+    let requests: Vec<ServiceRequest> = (0..REQUESTS).map(|_| request()).collect();
+    let start = Instant::now();
+    for req in &requests {
+        for _ in 0..lookups {
+            let info = req.connection_info();
+            black_box((info.host(), info.peer_addr(), info.realip_remote_addr()));
+        }
+    }
+    start.elapsed() / REQUESTS
+}
+
+fn main() {
+    let once = measure(1);
+    let thrice = measure(3);
+    println!("1 lookup/request   {:>8?}", once);
+    println!("3 lookups/request  {:>8?}", thrice);
+    println!("extra lookups      {:>8?}", thrice.saturating_sub(once));
+}
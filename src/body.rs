@@ -0,0 +1,126 @@
+use std::cell::RefCell;
+use std::pin::Pin;
+use std::rc::Rc;
+use std::task::{Context, Poll};
+
+use actix_http::BoxedPayloadStream;
+use actix_web::dev::{Payload, ServiceRequest};
+use actix_web::error::PayloadError;
+use actix_web::http::header::{self, HeaderMap};
+use actix_web::{HttpMessage, HttpRequest};
+use bytes::Bytes;
+use futures_core::Stream;
+
+/// Leading bytes of a body captured for logging, shared with the stream that reads the body.
+type Captured = Rc<RefCell<Vec<u8>>>;
+
+/// Request body captured by [`capture_request_body`], stored in the request extensions.
+struct CapturedRequestBody(Captured);
+
+/// Replace the payload of `req` with one that copies its first `max_bytes` into a buffer kept in
+/// the request extensions while the handler reads it.
+///
+/// The payload is passed through unchanged, nothing is read ahead of the handler. Only the bytes
+/// the handler actually read are captured.
+pub(crate) fn capture_request_body(req: &mut ServiceRequest, max_bytes: usize) {
+    let captured = Captured::default();
+    let payload: BoxedPayloadStream = Box::pin(CapturePayload {
+        payload: req.take_payload(),
+        captured: Rc::clone(&captured),
+        max_bytes,
+    });
+    req.set_payload(Payload::from(payload));
+    req.extensions_mut().insert(CapturedRequestBody(captured));
+}
+
+/// Request body captured so far for `req` as lossy UTF-8, if it is captured.
+pub(crate) fn captured_request_body(req: &HttpRequest) -> Option<String> {
+    let extensions = req.extensions();
+    let captured = extensions.get::<CapturedRequestBody>()?;
+    Some(String::from_utf8_lossy(&captured.0.borrow()).into_owned())
+}
+
+/// Append the part of `chunk` that still fits in `max_bytes` to `captured`.
+pub(crate) fn capture(captured: &mut Vec<u8>, max_bytes: usize, chunk: &[u8]) {
+    let len = max_bytes.saturating_sub(captured.len()).min(chunk.len());
+    captured.extend_from_slice(&chunk[..len]);
+}
+
+/// Whether the `Content-Type` in `headers` names a textual body worth logging.
+///
+/// Bodies without a content type are treated as binary.
+pub(crate) fn is_text(headers: &HeaderMap) -> bool {
+    let Some(content_type) = headers
+        .get(header::CONTENT_TYPE)
+        .and_then(|val| val.to_str().ok())
+    else {
+        return false;
+    };
+    let mime = content_type
+        .split(';')
+        .next()
+        .unwrap_or_default()
+        .trim()
+        .to_ascii_lowercase();
+    mime.starts_with("text/")
+        || mime.ends_with("+json")
+        || mime.ends_with("+xml")
+        || matches!(
+            mime.as_str(),
+            "application/json"
+                | "application/xml"
+                | "application/javascript"
+                | "application/x-www-form-urlencoded"
+        )
+}
+
+struct CapturePayload {
+    payload: Payload,
+    captured: Captured,
+    max_bytes: usize,
+}
+
+impl Stream for CapturePayload {
+    type Item = Result<Bytes, PayloadError>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let item = Pin::new(&mut self.payload).poll_next(cx);
+        if let Poll::Ready(Some(Ok(chunk))) = &item {
+            capture(&mut self.captured.borrow_mut(), self.max_bytes, chunk);
+        }
+        item
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_capture_stops_at_max_bytes() {
+        let mut captured = Vec::new();
+        capture(&mut captured, 5, b"abc");
+        capture(&mut captured, 5, b"defgh");
+        capture(&mut captured, 5, b"ijk");
+        assert_eq!(captured, b"abcde");
+    }
+
+    #[test]
+    fn test_is_text() {
+        let is_text = |content_type: Option<&str>| {
+            let mut headers = HeaderMap::new();
+            if let Some(content_type) = content_type {
+                headers.insert(header::CONTENT_TYPE, content_type.parse().unwrap());
+            }
+            super::is_text(&headers)
+        };
+
+        assert!(is_text(Some("text/html; charset=utf-8")));
+        assert!(is_text(Some("application/json")));
+        assert!(is_text(Some("application/problem+json")));
+        assert!(is_text(Some("Application/XML")));
+        assert!(!is_text(Some("image/png")));
+        assert!(!is_text(Some("application/octet-stream")));
+        assert!(!is_text(None));
+    }
+}
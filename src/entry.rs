@@ -0,0 +1,101 @@
+use crate::logger::Field;
+
+/// Rendered access log entry.
+///
+/// Wraps the fields collected for a request once they are all rendered to [`Field::KV`]. Fields
+/// that are not rendered are skipped.
+///
+/// With the `serde` feature enabled `LogEntry` implements `serde::Serialize` as a map of keys to
+/// values, so it can be written with any serializer (JSON, CBOR, MessagePack, ...). Fields
+/// without a value are serialized as `null`.
+#[derive(Clone, Copy, Debug)]
+pub struct LogEntry<'a>(&'a [Field]);
+
+impl<'a> LogEntry<'a> {
+    /// Wrap `fields`, of which only the ones rendered to [`Field::KV`] are part of the entry.
+    pub fn new(fields: &'a [Field]) -> Self {
+        LogEntry(fields)
+    }
+
+    /// Iterate over the rendered key/value pairs in output order.
+    pub fn iter(&self) -> impl Iterator<Item = (&'a str, Option<&'a str>)> + 'a {
+        self.0.iter().filter_map(|field| match field {
+            Field::KV(k, v) => Some((k.as_ref(), v.as_deref())),
+            _ => None,
+        })
+    }
+
+    /// Value of the first field with `key`. `Some(None)` means the field is present without a
+    /// value.
+    pub fn get(&self, key: &str) -> Option<Option<&'a str>> {
+        self.iter().find(|(k, _)| *k == key).map(|(_, v)| v)
+    }
+
+    /// All wrapped fields, including the ones not rendered.
+    pub fn fields(&self) -> &'a [Field] {
+        self.0
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for LogEntry<'_> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        use serde::ser::SerializeMap;
+
+        let mut map = serializer.serialize_map(None)?;
+        for (k, v) in self.iter() {
+            map.serialize_entry(k, &v)?;
+        }
+        map.end()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn fields() -> Vec<Field> {
+        vec![
+            Field::KV("method".into(), Some("GET".to_string())),
+            Field::KV("status".into(), Some("200".to_string())),
+            Field::KV("referer".into(), None),
+            Field::Size,
+        ]
+    }
+
+    #[test]
+    fn test_log_entry_iter() {
+        let fields = fields();
+        let entry = LogEntry::new(&fields);
+
+        assert_eq!(
+            entry.iter().collect::<Vec<_>>(),
+            vec![
+                ("method", Some("GET")),
+                ("status", Some("200")),
+                ("referer", None)
+            ]
+        );
+        assert_eq!(entry.get("method"), Some(Some("GET")));
+        assert_eq!(entry.get("referer"), Some(None));
+        assert_eq!(entry.get("size"), None);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_log_entry_serialize() {
+        let fields = fields();
+        let entry = LogEntry::new(&fields);
+
+        assert_eq!(
+            serde_json::to_string(&entry).unwrap(),
+            r#"{"method":"GET","status":"200","referer":null}"#
+        );
+
+        let value = serde_json::to_value(entry).unwrap();
+        assert_eq!(
+            value,
+            serde_json::json!({"method": "GET", "status": "200", "referer": null})
+        );
+    }
+}
@@ -0,0 +1,39 @@
+use std::fmt;
+
+/// Configuration problem reported by [`SLogger::validate`](crate::SLogger::validate).
+#[derive(Clone, Debug)]
+pub enum SLoggerError {
+    /// An exclusion pattern is not a valid regex.
+    InvalidRegex(regex::Error),
+    /// A header to log is not a valid header name.
+    InvalidHeaderName(String),
+    /// No fields are configured, every record would be empty.
+    EmptyFields,
+    /// An option has no effect because the field it applies to is not logged.
+    UnusedOption {
+        option: &'static str,
+        field: &'static str,
+    },
+}
+
+impl fmt::Display for SLoggerError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SLoggerError::InvalidRegex(err) => write!(f, "invalid exclusion regex: {err}"),
+            SLoggerError::InvalidHeaderName(name) => write!(f, "invalid header name: {name:?}"),
+            SLoggerError::EmptyFields => f.write_str("no fields to log"),
+            SLoggerError::UnusedOption { option, field } => {
+                write!(f, "`{option}` has no effect without the `{field}` field")
+            }
+        }
+    }
+}
+
+impl std::error::Error for SLoggerError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            SLoggerError::InvalidRegex(err) => Some(err),
+            _ => None,
+        }
+    }
+}
@@ -112,31 +112,106 @@
 //!
 //! - `method` - HTTP method (GET, POST, etc.)
 //! - `status` - Response status code
+//! - `response_header_count` - Number of response headers set by the application
+//! - `grpc_status` - gRPC status code from the `grpc-status` header, not read from trailers
 //! - `path` - Request path
+//! - `route` - Matched route pattern, like `/users/{id}`
 //! - `params` - Query parameters
+//! - `query.<name>` - Each query parameter, with `with_params_expanded`
 //! - `version` - HTTP protocol version
 //! - `host` - Request host
+//! - `url` - Absolute URL of the request, `scheme://host/path?query`
 //! - `remote_addr` - Client IP address
 //! - `real_ip` - Client real IP (when behind proxy)
+//! - `client_ip` - Client IP behind the trusted proxies, safe against spoofed `X-Forwarded-For`
+//! - `forwarded_for` - Raw `X-Forwarded-For` chain, every proxy hop
+//! - `header_count` - Number of request headers, repeated headers counted per value
 //! - `request_id` - Auto-generated or extracted request ID
 //! - `size` - Response size in bytes
+//! - `throughput` - Response bytes sent per second
+//! - `size_human` - Response size in 1024 based units, like `1.2 KiB`
 //! - `duration` - Request duration in seconds
 //! - `duration_millis` - Request duration in milliseconds
-//! - `datetime` - Timestamp in RFC3339 format
+//! - `duration_nanos` - Request duration in whole nanoseconds
+//! - `latency_bucket` - Latency bucket of the request, like `<=100ms` or `>1s`
+//! - `datetime` - Timestamp in RFC3339 format, or another `DateTimeFormat`
 //! - `user_agent` - Client user agent
-//! - `referer` - Request referrer
-//!
-//! You can also log custom request headers, response headers, and environment variables.
+//! - `referer` - Request referrer, only scheme, host and path with `with_referer_sanitized`
+//! - `scope` - Scope marker inserted by a scope middleware
+//! - `host_sni_match` - Whether the TLS SNI matches the request host (`tls` feature)
+//! - `tls_version`, `tls_cipher` - Negotiated TLS protocol version and cipher suite (`tls` feature)
+//! - `alpn` - Protocol negotiated with ALPN, inferred from the HTTP version without TLS (`tls` feature)
+//! - `poll_count` - Number of times the response body was polled
+//! - `queue_time_ms` - Time spent queued before the request reached the server, from `X-Request-Start`
+//! - `if_none_match`, `if_modified_since` - Conditional request headers
+//! - `connection_id` - Id of the client connection, see `ConnectionId`
+//! - `multipart_boundary` - Boundary of multipart request bodies
+//! - `size_mismatch` - Whether the response size differs from the declared `Content-Length`
+//! - `auth_scheme` - Scheme of the `Authorization` header, without the credentials
+//! - `scheme` - Request scheme (http/https)
+//! - `port` - Local port of the listener that accepted the request
+//! - `request_size` - Request body size in bytes from `Content-Length`
+//! - `error` - Message of the error that produced the response
+//! - `span.name`, `span.target` - Current `tracing` span when the request starts, with the `tracing` feature
+//! - `http2` - Whether the request uses HTTP/2
+//! - `secure` - Whether the request scheme is https
+//! - `seq` - Sequence number of the request within the worker
+//! - `request_start`, `request_end` - Times the request started and the response completed
+//! - `chunks` - Number of chunks produced by the response body
+//! - `empty_body` - Whether the response has no body
+//! - `inflight` - Requests in flight within the worker when the request completes
+//! - `worker_id` - Id of the worker thread that handled the request
+//! - `request_body` - Leading bytes of the request body (`debug-body` feature, debugging only)
+//! - `response_body` - Leading bytes of a textual response body (`debug-body` feature, debugging only)
+//!
+//! You can also log custom request headers, response headers, cookies (`cookies` feature), and
+//! environment variables.
+//! Headers logged with `with_request_headers` and `with_response_headers` are nested under
+//! `headers.` and `response_headers.` keys, e.g. `headers.accept_language`.
 //!
 //! # Feature Flags
 //!
 //! - `log` (default) - Enable integration with the standard `log` crate
 //! - `tracing-request-id` - Enable integration with `tracing-actix-web`'s request ID
-//! - `uuid_v7` - Use UUIDv7 instead of UUIDv4 for request IDs
+//! - `uuid_v7` - Use UUIDv7 instead of UUIDv4 for request IDs, see `SLogger::request_id_version`
+//! - `tls` - Enable TLS related fields, see `TlsInfo`
+//! - `serde` - Implement `serde::Serialize` for `LogEntry`
+//! - `json` - Write records as JSON lines with `SLogger::json_writer`
+//! - `debug-body` - Log the leading bytes of request and response bodies, for debugging only
+//! - `slog` - Emit records to a `slog` logger with `SLogger::slog_logger`
+//! - `tracing` - Allow emitting records as `tracing` events instead of through the `log` crate
+//!   (leaks up to 1024 callsites, one per distinct level, target and set of keys)
+//! - `cookies` - Log request cookies with `Fields::with_cookie`
 
+#[cfg(feature = "debug-body")]
+mod body;
+mod entry;
+mod error;
 mod logger;
+#[cfg(feature = "log")]
+mod sink;
+#[cfg(feature = "tls")]
+mod tls;
 mod wrapper;
+mod writer;
 
-pub use crate::logger::RequestId;
-pub use crate::logger::{Fields, SLogger};
+pub use crate::entry::LogEntry;
+pub use crate::error::SLoggerError;
+pub use crate::logger::{
+    CommonLogFormat, CustomField, DateTimeFormat, Field, FieldCondition, Fields, HeaderKeyStyle,
+    InvalidHeaderPolicy, KeyStyle, Redaction, SLogger, SLoggerBuilder,
+};
+pub use crate::logger::{
+    ConnectionId, LoggedRequestId, RequestId, RequestIdFormat, RequestIdVersion, ScopeMarker,
+};
+#[cfg(feature = "log")]
+pub use crate::sink::{AsyncSink, LogSink};
+#[cfg(feature = "tls")]
+pub use crate::tls::TlsInfo;
+#[cfg(feature = "log")]
 pub use crate::wrapper::rust_log;
+#[cfg(feature = "slog")]
+pub use crate::wrapper::slog_log;
+#[cfg(feature = "tracing")]
+pub use crate::wrapper::tracing_log;
+pub use ipnet::IpNet;
@@ -0,0 +1,156 @@
+use std::borrow::Cow;
+use std::sync::mpsc::{self, Receiver, SyncSender};
+use std::thread;
+
+use crate::logger::Field;
+
+/// Destination of access log records.
+///
+/// By default records are emitted through the `log` crate. Implement this trait to route them
+/// elsewhere and install the implementation with [`SLogger::sink`](crate::SLogger::sink).
+///
+/// # Examples
+/// ```rust
+/// use actix_web_middleware_slogger::{Field, LogSink, SLogger};
+///
+/// struct Stdout;
+///
+/// impl LogSink for Stdout {
+///     fn emit(&self, level: log::Level, target: &str, fields: &[Field]) {
+///         println!("{level} {target} {} fields", fields.len());
+///     }
+/// }
+///
+/// let logger = SLogger::default().sink(Stdout);
+/// ```
+pub trait LogSink {
+    /// Emit one record with its rendered `fields`.
+    ///
+    /// Only [`Field::KV`] variants are present in `fields` once a record is complete.
+    fn emit(&self, level: log::Level, target: &str, fields: &[Field]);
+}
+
+impl<F> LogSink for F
+where
+    F: Fn(log::Level, &str, &[Field]),
+{
+    fn emit(&self, level: log::Level, target: &str, fields: &[Field]) {
+        self(level, target, fields)
+    }
+}
+
+/// Rendered record handed over to the background thread of an [`AsyncSink`].
+struct AsyncRecord {
+    level: log::Level,
+    target: String,
+    kvs: Vec<(Cow<'static, str>, Option<String>)>,
+}
+
+/// Sink that emits records through the `log` crate from a background thread.
+///
+/// Records are queued in a bounded channel so completing a response never waits for a slow
+/// logger. When the queue is full the record is dropped. The thread stops once the logger is
+/// dropped and the queued records are emitted.
+///
+/// Every `AsyncSink` owns one OS thread. An app factory passed to `HttpServer::new` runs once
+/// per worker, so creating the sink inside it spawns a thread per worker. Create the sink once
+/// and clone it into the factory to share a single thread between workers.
+///
+/// Install it with [`SLogger::async_sink`](crate::SLogger::async_sink) or
+/// [`SLogger::sink`](crate::SLogger::sink).
+///
+/// # Examples
+/// ```rust
+/// use actix_web::{App, HttpServer};
+/// use actix_web_middleware_slogger::{AsyncSink, SLogger};
+///
+/// let sink = AsyncSink::new(1024);
+/// let server = HttpServer::new(move || {
+///     App::new().wrap(SLogger::default().sink(sink.clone()))
+/// });
+/// ```
+#[derive(Clone)]
+pub struct AsyncSink {
+    sender: SyncSender<AsyncRecord>,
+}
+
+impl AsyncSink {
+    /// Spawn the background thread with a queue of `capacity` records.
+    ///
+    /// # Panics
+    /// Panics if `capacity` is zero, since every record would then wait for the thread to take
+    /// it, or if the thread can not be spawned.
+    pub fn new(capacity: usize) -> Self {
+        assert!(capacity > 0, "async sink capacity must be at least 1");
+        let (sender, receiver) = mpsc::sync_channel(capacity);
+        thread::Builder::new()
+            .name("slogger-async-sink".to_string())
+            .spawn(move || Self::run(receiver))
+            .expect("failed to spawn async sink thread");
+        AsyncSink { sender }
+    }
+
+    fn run(receiver: Receiver<AsyncRecord>) {
+        for record in receiver {
+            let fields: Vec<Field> = record
+                .kvs
+                .into_iter()
+                .map(|(key, value)| Field::KV(key, value))
+                .collect();
+            crate::wrapper::rust_log::log_message(
+                record.level,
+                &record.target,
+                module_path!(),
+                std::panic::Location::caller(),
+                "access log",
+                &fields,
+            );
+        }
+    }
+}
+
+impl LogSink for AsyncSink {
+    fn emit(&self, level: log::Level, target: &str, fields: &[Field]) {
+        let kvs = fields
+            .iter()
+            .filter_map(|field| match field {
+                Field::KV(key, value) => Some((key.clone(), value.clone())),
+                _ => None,
+            })
+            .collect();
+        let record = AsyncRecord {
+            level,
+            target: target.to_string(),
+            kvs,
+        };
+        // the record is dropped if the queue is full or the thread is gone
+        let _ = self.sender.try_send(record);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_async_sink_drops_when_full() {
+        let (sender, receiver) = mpsc::sync_channel(1);
+        let sink = AsyncSink { sender };
+
+        let fields = [Field::KV("path".into(), Some("/".to_string()))];
+        sink.emit(log::Level::Info, "access", &fields);
+        sink.emit(log::Level::Warn, "access", &fields);
+
+        let record = receiver.try_recv().unwrap();
+        assert_eq!(record.level, log::Level::Info);
+        assert_eq!(record.target, "access");
+        assert_eq!(record.kvs, [("path".into(), Some("/".to_string()))]);
+        assert!(receiver.try_recv().is_err());
+    }
+
+    #[test]
+    #[should_panic(expected = "async sink capacity must be at least 1")]
+    fn test_async_sink_rejects_zero_capacity() {
+        AsyncSink::new(0);
+    }
+}
@@ -0,0 +1,60 @@
+use actix_web::HttpMessage;
+use actix_web::dev::ServiceRequest;
+
+/// TLS session details of the connection that carried the request.
+///
+/// `SLogger` does not depend on a particular TLS implementation, so this value has to be provided
+/// by the application. Insert it as connection data from `HttpServer::on_connect` (preferred) or
+/// into the request extensions from a middleware that runs before `SLogger`.
+///
+/// No actix-tls version stores the negotiated TLS parameters in the request extensions on its own.
+/// With actix-tls 3 and rustls, take them from the `ServerConnection` of the `TlsStream` passed to
+/// `on_connect`: `protocol_version()`, `negotiated_cipher_suite()` and `alpn_protocol()`.
+///
+/// # Examples
+/// ```rust
+/// use actix_web::{App, HttpServer};
+/// use actix_web_middleware_slogger::TlsInfo;
+///
+/// let server = HttpServer::new(App::new).on_connect(|_conn, data| {
+///     // Extract the SNI from the TLS stream of `_conn` here.
+///     data.insert(TlsInfo {
+///         sni: Some("example.com".to_string()),
+///         ..TlsInfo::default()
+///     });
+/// });
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct TlsInfo {
+    /// Server name sent by the client in the TLS handshake.
+    pub sni: Option<String>,
+    /// Negotiated protocol version. Example: TLSv1.3
+    pub version: Option<String>,
+    /// Negotiated cipher suite. Example: TLS13_AES_256_GCM_SHA384
+    pub cipher: Option<String>,
+    /// Protocol negotiated with ALPN. Example: h2
+    pub alpn: Option<String>,
+}
+
+/// Call `f` with the [`TlsInfo`] of the request, if there is any.
+pub(crate) fn with_tls_info<R>(req: &ServiceRequest, f: impl FnOnce(&TlsInfo) -> R) -> Option<R> {
+    match req.conn_data::<TlsInfo>() {
+        Some(info) => Some(f(info)),
+        None => req.extensions().get::<TlsInfo>().map(f),
+    }
+}
+
+/// Host the client asked for, without the port.
+pub(crate) fn request_host(req: &ServiceRequest) -> Option<&str> {
+    let host = match req.headers().get(actix_web::http::header::HOST) {
+        Some(val) => val.to_str().ok()?,
+        None => req.uri().host()?,
+    };
+
+    // Keep IPv6 literals like `[::1]:8080` intact up to the closing bracket
+    let host = match host.rfind(']') {
+        Some(end) => &host[..=end],
+        None => host.split(':').next().unwrap_or(host),
+    };
+    Some(host)
+}
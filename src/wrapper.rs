@@ -3,6 +3,28 @@ pub mod rust_log {
     use crate::logger::Field;
     use std::panic::Location;
 
+    /// Rendered fields passed to `log` as record key-values without collecting them.
+    struct FieldsSource<'a>(&'a [Field]);
+
+    impl log::kv::Source for FieldsSource<'_> {
+        fn visit<'kvs>(
+            &'kvs self,
+            visitor: &mut dyn log::kv::VisitSource<'kvs>,
+        ) -> Result<(), log::kv::Error> {
+            for field in self.0 {
+                if let Field::KV(k, v) = field {
+                    let value = match v {
+                        Some(v) => log::kv::Value::from_display(v),
+                        None => log::kv::Value::null(),
+                    };
+                    visitor.visit_pair(log::kv::Key::from_str(k), value)?;
+                }
+            }
+            Ok(())
+        }
+    }
+
+    /// Log `kv_fields` as the key-values of an `access log` record.
     pub fn log(
         level: log::Level,
         target: &str,
@@ -10,29 +32,261 @@ pub mod rust_log {
         loc: &'static Location,
         kv_fields: Vec<Field>,
     ) {
-        let kvs: Vec<(&str, log::kv::Value)> = kv_fields
-            .iter()
-            .filter_map(|field| match field {
-                Field::KV(k, v) => match v {
-                    Some(v) => Some((k.as_str(), log::kv::Value::from_display(v))),
-                    None => Some((k.as_str(), log::kv::Value::null())),
-                },
-                _ => None,
+        log_message(level, target, module_path, loc, "access log", &kv_fields);
+    }
+
+    /// Log `kv_fields` as the key-values of a record with `message`.
+    pub fn log_message(
+        level: log::Level,
+        target: &str,
+        module_path: &'static str,
+        loc: &'static Location,
+        message: &str,
+        kv_fields: &[Field],
+    ) {
+        let kvs = FieldsSource(kv_fields);
+
+        log::logger().log(
+            &log::Record::builder()
+                .args(format_args!("{message}"))
+                .level(level)
+                .target(target)
+                .module_path_static(Some(module_path))
+                .file_static(Some(loc.file()))
+                .line(Some(loc.line()))
+                .key_values(&kvs)
+                .build(),
+        );
+    }
+}
+
+#[cfg(feature = "slog")]
+pub mod slog_log {
+    use crate::logger::Field;
+    use std::borrow::Cow;
+
+    /// Rendered fields passed to `slog` as record key-values.
+    struct FieldsKV<'a>(&'a [Field]);
+
+    impl slog::KV for FieldsKV<'_> {
+        fn serialize(
+            &self,
+            _record: &slog::Record,
+            serializer: &mut dyn slog::Serializer,
+        ) -> slog::Result {
+            for field in self.0 {
+                if let Field::KV(k, v) = field {
+                    let key = match k {
+                        Cow::Borrowed(k) => slog::Key::from(*k),
+                        Cow::Owned(k) => slog::Key::from(k.clone()),
+                    };
+                    match v {
+                        Some(v) => serializer.emit_str(key, v)?,
+                        None => serializer.emit_none(key)?,
+                    }
+                }
+            }
+            Ok(())
+        }
+    }
+
+    #[cfg(feature = "log")]
+    pub fn level(level: log::Level) -> slog::Level {
+        match level {
+            log::Level::Error => slog::Level::Error,
+            log::Level::Warn => slog::Level::Warning,
+            log::Level::Info => slog::Level::Info,
+            log::Level::Debug => slog::Level::Debug,
+            log::Level::Trace => slog::Level::Trace,
+        }
+    }
+
+    pub fn log(
+        logger: &slog::Logger,
+        level: slog::Level,
+        target: &str,
+        message: &str,
+        kv_fields: &[Field],
+    ) {
+        let record_static = slog::record_static!(level, target);
+        let kvs = FieldsKV(kv_fields);
+
+        logger.log(&slog::Record::new(
+            &record_static,
+            &format_args!("{message}"),
+            slog::BorrowedKV(&kvs),
+        ));
+    }
+}
+
+#[cfg(feature = "tracing")]
+pub mod tracing_log {
+    use crate::logger::Field;
+    use std::collections::HashMap;
+    use std::hash::{DefaultHasher, Hash, Hasher};
+    use std::sync::{LazyLock, Mutex, OnceLock, PoisonError};
+    use tracing::callsite::{Callsite, Identifier};
+    use tracing::field::{FieldSet, Value};
+    use tracing::level_filters::LevelFilter;
+    use tracing::metadata::Kind;
+    use tracing::subscriber::Interest;
+    use tracing::{Event, Level, Metadata};
+
+    pub use crate::writer::FieldsDisplay;
+
+    /// Most callsites created for distinct targets, levels and keys. Records with a new set of
+    /// keys past this limit are emitted with a single `fields` value instead.
+    const MAX_CALLSITES: usize = 1024;
+
+    /// Most values an event carries, the message included. Records with more fields are
+    /// emitted with a single `fields` value instead.
+    const MAX_VALUES: usize = 64;
+
+    /// A callsite created at runtime, since `tracing` needs the field names of an event up
+    /// front and the keys of a record depend on the configuration and the request.
+    struct DynamicCallsite(OnceLock<Metadata<'static>>);
+
+    impl Callsite for DynamicCallsite {
+        fn set_interest(&self, _: Interest) {}
+
+        fn metadata(&self) -> &Metadata<'_> {
+            self.0
+                .get()
+                .expect("metadata is set when the callsite is created")
+        }
+    }
+
+    /// Callsites by a hash of their level, target and keys.
+    #[derive(Default)]
+    struct Callsites {
+        by_hash: HashMap<u64, Vec<&'static Metadata<'static>>>,
+        len: usize,
+    }
+
+    static CALLSITES: LazyLock<Mutex<Callsites>> = LazyLock::new(Default::default);
+
+    fn leak(s: &str) -> &'static str {
+        Box::leak(s.into())
+    }
+
+    /// Metadata for an event at `level` to `target` with a `message` and `keys`, created and
+    /// registered the first time it is seen. Returns `None` once the limit of callsites is
+    /// reached, unless `force` is set.
+    fn metadata<'a>(
+        level: Level,
+        target: &str,
+        keys: impl Iterator<Item = &'a str> + Clone,
+        force: bool,
+    ) -> Option<&'static Metadata<'static>> {
+        let mut hasher = DefaultHasher::new();
+        level.hash(&mut hasher);
+        target.hash(&mut hasher);
+        keys.clone().for_each(|key| key.hash(&mut hasher));
+        let hash = hasher.finish();
+
+        let mut callsites = CALLSITES.lock().unwrap_or_else(PoisonError::into_inner);
+        let found = callsites.by_hash.get(&hash).and_then(|candidates| {
+            candidates.iter().copied().find(|meta| {
+                *meta.level() == level
+                    && meta.target() == target
+                    && meta
+                        .fields()
+                        .iter()
+                        .skip(1)
+                        .map(|f| f.name())
+                        .eq(keys.clone())
             })
-            .collect();
-        let kvs = kvs.as_slice();
+        });
+        if found.is_some() || (callsites.len >= MAX_CALLSITES && !force) {
+            return found;
+        }
+
+        let names: Vec<&'static str> = std::iter::once("message").chain(keys.map(leak)).collect();
+        let callsite: &'static DynamicCallsite =
+            Box::leak(Box::new(DynamicCallsite(OnceLock::new())));
+        let meta = callsite.0.get_or_init(|| {
+            Metadata::new(
+                "access log",
+                leak(target),
+                level,
+                None,
+                None,
+                None,
+                FieldSet::new(Box::leak(names.into_boxed_slice()), Identifier(callsite)),
+                Kind::EVENT,
+            )
+        });
+        tracing::callsite::register(callsite);
+        callsites.by_hash.entry(hash).or_default().push(meta);
+        callsites.len += 1;
+        Some(meta)
+    }
 
-        let mut builder = log::Record::builder();
+    /// Dispatch an event for `meta` with `values` in the order of its fields.
+    ///
+    /// `tracing` only builds value sets from fixed-size arrays, so the values are padded to
+    /// `MAX_VALUES` with empty entries for the first field, which visitors skip.
+    fn dispatch(meta: &'static Metadata<'static>, values: &[Option<&dyn Value>]) {
+        let fields = meta.fields();
+        let keys: Vec<_> = fields.iter().collect();
+        let values: [_; MAX_VALUES] = std::array::from_fn(|i| {
+            (
+                keys.get(i).unwrap_or(&keys[0]),
+                values.get(i).copied().flatten(),
+            )
+        });
+        let values = fields.value_set(&values);
+        tracing::dispatcher::get_default(|dispatch| {
+            if dispatch.enabled(meta) {
+                dispatch.event(&Event::new(meta, &values));
+            }
+        });
+    }
 
-        builder
-            .args(format_args!("access log"))
-            .level(level)
-            .target(target)
-            .module_path_static(Some(module_path))
-            .file_static(Some(loc.file()))
-            .line(Some(loc.line()))
-            .key_values(&kvs);
+    /// Emit `kv_fields` as a `tracing` event to `target`, with every field recorded as an
+    /// event field of the same name.
+    ///
+    /// Fields without a value are left out of the event. Records with too many fields, or with
+    /// a new set of keys once too many distinct sets were seen, record all the fields as a single
+    /// `fields` value instead.
+    pub fn log(level: Level, target: &str, message: &str, kv_fields: &[Field]) {
+        if level > LevelFilter::current() {
+            return;
+        }
+        let keys = kv_fields.iter().filter_map(|field| match field {
+            Field::KV(key, _) => Some(key.as_ref()),
+            _ => None,
+        });
+        let meta = (keys.clone().count() < MAX_VALUES)
+            .then(|| metadata(level, target, keys, false))
+            .flatten();
+        match meta {
+            Some(meta) => {
+                let values: Vec<Option<&dyn Value>> = std::iter::once(Some(&message as &dyn Value))
+                    .chain(kv_fields.iter().filter_map(|field| match field {
+                        Field::KV(_, value) => Some(value.as_ref().map(|v| v as &dyn Value)),
+                        _ => None,
+                    }))
+                    .collect();
+                dispatch(meta, &values);
+            }
+            None => {
+                let meta = metadata(level, target, std::iter::once("fields"), true)
+                    .expect("forced callsites are always created");
+                let fields = tracing::field::display(FieldsDisplay(kv_fields));
+                dispatch(meta, &[Some(&message), Some(&fields)]);
+            }
+        }
+    }
 
-        log::logger().log(&builder.build());
+    #[cfg(feature = "log")]
+    pub fn level(level: log::Level) -> tracing::Level {
+        match level {
+            log::Level::Error => tracing::Level::ERROR,
+            log::Level::Warn => tracing::Level::WARN,
+            log::Level::Info => tracing::Level::INFO,
+            log::Level::Debug => tracing::Level::DEBUG,
+            log::Level::Trace => tracing::Level::TRACE,
+        }
     }
 }
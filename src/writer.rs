@@ -0,0 +1,149 @@
+use std::fmt;
+use std::io::Write;
+use std::sync::{Arc, Mutex};
+
+use crate::logger::Field;
+
+/// Shared destination of the lines written by a [`LineWriter`].
+pub(crate) type SharedWrite = Arc<Mutex<dyn Write + Send>>;
+
+/// Format of the lines written by a [`LineWriter`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum LineFormat {
+    /// One JSON object per line.
+    #[cfg(feature = "json")]
+    Json,
+    /// Space separated `key=value` pairs, see [`FieldsDisplay`].
+    Logfmt,
+}
+
+/// Writes every record as a single line to an `io::Write`, without going through a logger.
+#[derive(Clone)]
+pub(crate) struct LineWriter {
+    format: LineFormat,
+    out: SharedWrite,
+}
+
+impl LineWriter {
+    pub(crate) fn new(format: LineFormat, out: SharedWrite) -> Self {
+        LineWriter { format, out }
+    }
+
+    /// Write the rendered `fields` as one line.
+    ///
+    /// The line is formatted before the writer is locked and written with a single call, so
+    /// records of concurrent workers never interleave. Write errors are ignored, a failing
+    /// destination must not fail the request.
+    pub(crate) fn write(&self, fields: &[Field]) {
+        let line = match self.format {
+            #[cfg(feature = "json")]
+            LineFormat::Json => match serde_json::to_vec(&crate::LogEntry::new(fields)) {
+                Ok(line) => line,
+                Err(_) => return,
+            },
+            LineFormat::Logfmt => FieldsDisplay(fields).to_string().into_bytes(),
+        };
+        self.write_bytes(line);
+    }
+
+    /// Write a preformatted `line`, ignoring the format of the writer.
+    pub(crate) fn write_line(&self, line: &str) {
+        self.write_bytes(line.as_bytes().to_vec());
+    }
+
+    fn write_bytes(&self, mut line: Vec<u8>) {
+        line.push(b'\n');
+        if let Ok(mut out) = self.out.lock() {
+            let _ = out.write_all(&line);
+        }
+    }
+}
+
+impl fmt::Debug for LineWriter {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("LineWriter")
+            .field("format", &self.format)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Rendered fields formatted as logfmt, space separated `key=value` pairs.
+///
+/// Values that are empty or contain whitespace, control characters, quotes or `=` are quoted with
+/// embedded quotes and control characters escaped. Absent values are left empty.
+pub struct FieldsDisplay<'a>(pub &'a [Field]);
+
+impl fmt::Display for FieldsDisplay<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut first = true;
+        for field in self.0 {
+            let Field::KV(k, v) = field else {
+                continue;
+            };
+            if !first {
+                f.write_str(" ")?;
+            }
+            first = false;
+            match v {
+                Some(v) if needs_quotes(v) => write!(f, "{k}={v:?}")?,
+                Some(v) => write!(f, "{k}={v}")?,
+                None => write!(f, "{k}=")?,
+            }
+        }
+        Ok(())
+    }
+}
+
+fn needs_quotes(value: &str) -> bool {
+    value.is_empty()
+        || value.contains(|c: char| c.is_whitespace() || c.is_control() || c == '"' || c == '=')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn write(format: LineFormat, fields: &[Field]) -> String {
+        let out = Arc::new(Mutex::new(Vec::new()));
+        LineWriter::new(format, out.clone()).write(fields);
+        let out = out.lock().unwrap();
+        String::from_utf8(out.clone()).unwrap()
+    }
+
+    #[cfg(feature = "json")]
+    #[test]
+    fn test_line_writer_json() {
+        let fields = [
+            Field::KV("method".into(), Some("GET".to_string())),
+            Field::KV("path".into(), Some("/a \"b\"".to_string())),
+            Field::KV("referer".into(), None),
+        ];
+
+        assert_eq!(
+            write(LineFormat::Json, &fields),
+            "{\"method\":\"GET\",\"path\":\"/a \\\"b\\\"\",\"referer\":null}\n"
+        );
+    }
+
+    #[test]
+    fn test_line_writer_logfmt() {
+        let fields = [
+            Field::KV("method".into(), Some("GET".to_string())),
+            Field::KV("params".into(), Some("a=1&b=2".to_string())),
+            Field::KV("user_agent".into(), Some("curl/8.0 (x86_64)".to_string())),
+            Field::KV("etag".into(), Some("\"abc\"".to_string())),
+            Field::KV("referer".into(), Some(String::new())),
+            Field::KV("real_ip".into(), None),
+            Field::KV("error".into(), Some("line\nbreak".to_string())),
+            Field::Size,
+        ];
+
+        assert_eq!(
+            write(LineFormat::Logfmt, &fields),
+            concat!(
+                "method=GET params=\"a=1&b=2\" user_agent=\"curl/8.0 (x86_64)\" ",
+                "etag=\"\\\"abc\\\"\" referer=\"\" real_ip= error=\"line\\nbreak\"\n"
+            )
+        );
+    }
+}